@@ -422,6 +422,7 @@ func issueVerdict(conn *network.Connection, pkt packet.Packet, verdict network.V
 	switch verdict {
 	case network.VerdictAccept:
 		atomic.AddUint64(packetsAccepted, 1)
+		acceptedPacketsCounter.Inc()
 		if conn.VerdictPermanent {
 			err = pkt.PermanentAccept()
 		} else {
@@ -429,6 +430,7 @@ func issueVerdict(conn *network.Connection, pkt packet.Packet, verdict network.V
 		}
 	case network.VerdictBlock:
 		atomic.AddUint64(packetsBlocked, 1)
+		blockedPacketsCounter.Inc()
 		if conn.VerdictPermanent {
 			err = pkt.PermanentBlock()
 		} else {
@@ -436,20 +438,25 @@ func issueVerdict(conn *network.Connection, pkt packet.Packet, verdict network.V
 		}
 	case network.VerdictDrop:
 		atomic.AddUint64(packetsDropped, 1)
+		droppedPacketsCounter.Inc()
 		if conn.VerdictPermanent {
 			err = pkt.PermanentDrop()
 		} else {
 			err = pkt.Drop()
 		}
 	case network.VerdictRerouteToNameserver:
+		reroutedToNameserverPacketsCounter.Inc()
 		err = pkt.RerouteToNameserver()
 	case network.VerdictRerouteToTunnel:
+		reroutedToTunnelPacketsCounter.Inc()
 		err = pkt.RerouteToTunnel()
 	case network.VerdictFailed:
 		atomic.AddUint64(packetsFailed, 1)
+		failedPacketsCounter.Inc()
 		err = pkt.Drop()
 	default:
 		atomic.AddUint64(packetsDropped, 1)
+		droppedPacketsCounter.Inc()
 		err = pkt.Drop()
 	}
 
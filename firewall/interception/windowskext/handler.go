@@ -114,6 +114,11 @@ func Handler(packets chan packet.Packet) {
 				info.Src = convertIPv6(packetInfo.localIP)
 				info.Dst = convertIPv6(packetInfo.remoteIP)
 			}
+
+			// Dual-stack sockets may report IPv4 connections with IPv4-mapped
+			// IPv6 addresses (::ffff:a.b.c.d). Handle them as IPv4, so that they
+			// match the same connection when seen via IPv4 in the network stack.
+			normalizeIPv4MappedIPv6(info)
 		}
 
 		// Ports
@@ -138,6 +143,23 @@ func convertIPv4(input [4]uint32) net.IP {
 	return net.IP(addressBuf)
 }
 
+// normalizeIPv4MappedIPv6 converts an IPv6 packet info with IPv4-mapped
+// addresses to IPv4. If only one address is IPv4-mapped, the info is left as
+// is, as this cannot be a valid IPv4 connection.
+func normalizeIPv4MappedIPv6(info *packet.Info) {
+	src4 := info.Src.To4()
+	dst4 := info.Dst.To4()
+
+	switch {
+	case src4 != nil && dst4 != nil:
+		info.Version = packet.IPv4
+		info.Src = src4
+		info.Dst = dst4
+	case src4 != nil || dst4 != nil:
+		log.Debugf("windowskext: received IPv6 verdict request with only one IPv4-mapped address: %s -> %s", info.Src, info.Dst)
+	}
+}
+
 func convertIPv6(input [4]uint32) net.IP {
 	addressBuf := make([]byte, 16)
 	for i := 0; i < 4; i++ {
@@ -213,6 +213,12 @@ func GetPayload(packetID uint32, packetSize uint32) ([]byte, error) {
 		return nil, ErrNoPacketID
 	}
 
+	// Check for an empty packet before allocating, as the buffer is passed to
+	// the kext by the address of its first element.
+	if packetSize == 0 {
+		return nil, errors.New("packet size reported by windows kext is zero")
+	}
+
 	kextLock.RLock()
 	defer kextLock.RUnlock()
 	if !ready.IsSet() {
@@ -6,7 +6,17 @@
 	"github.com/safing/portbase/metrics"
 )
 
-var packetHandlingHistogram *metrics.Histogram
+var (
+	packetHandlingHistogram *metrics.Histogram
+
+	acceptedPacketsCounter *metrics.Counter
+	blockedPacketsCounter  *metrics.Counter
+	droppedPacketsCounter  *metrics.Counter
+	failedPacketsCounter   *metrics.Counter
+
+	reroutedToNameserverPacketsCounter *metrics.Counter
+	reroutedToTunnelPacketsCounter     *metrics.Counter
+)
 
 func registerMetrics() (err error) {
 	packetHandlingHistogram, err = metrics.NewHistogram(
@@ -16,6 +26,82 @@ func registerMetrics() (err error) {
 			Permission:     api.PermitUser,
 			ExpertiseLevel: config.ExpertiseLevelExpert,
 		})
+	if err != nil {
+		return err
+	}
+
+	verdictCounterID := "firewall/packets/verdicts/total"
+	verdictCounterOpts := &metrics.Options{
+		Name:           "Packet Verdicts",
+		Permission:     api.PermitUser,
+		ExpertiseLevel: config.ExpertiseLevelExpert,
+	}
+
+	acceptedPacketsCounter, err = metrics.NewCounter(
+		verdictCounterID,
+		map[string]string{
+			"verdict": "accepted",
+		},
+		verdictCounterOpts,
+	)
+	if err != nil {
+		return err
+	}
+
+	blockedPacketsCounter, err = metrics.NewCounter(
+		verdictCounterID,
+		map[string]string{
+			"verdict": "blocked",
+		},
+		verdictCounterOpts,
+	)
+	if err != nil {
+		return err
+	}
+
+	droppedPacketsCounter, err = metrics.NewCounter(
+		verdictCounterID,
+		map[string]string{
+			"verdict": "dropped",
+		},
+		verdictCounterOpts,
+	)
+	if err != nil {
+		return err
+	}
+
+	failedPacketsCounter, err = metrics.NewCounter(
+		verdictCounterID,
+		map[string]string{
+			"verdict": "failed",
+		},
+		verdictCounterOpts,
+	)
+	if err != nil {
+		return err
+	}
+
+	reroutedToNameserverPacketsCounter, err = metrics.NewCounter(
+		verdictCounterID,
+		map[string]string{
+			"verdict": "rerouted_to_nameserver",
+		},
+		verdictCounterOpts,
+	)
+	if err != nil {
+		return err
+	}
+
+	reroutedToTunnelPacketsCounter, err = metrics.NewCounter(
+		verdictCounterID,
+		map[string]string{
+			"verdict": "rerouted_to_tunnel",
+		},
+		verdictCounterOpts,
+	)
+	if err != nil {
+		return err
+	}
 
-	return err
+	return nil
 }
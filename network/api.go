@@ -313,5 +313,12 @@ func (a connectionsByGroup) Less(i, j int) bool {
 	}
 
 	// 4. Started
-	return a[i].Started < a[j].Started
+	if a[i].Started != a[j].Started {
+		return a[i].Started < a[j].Started
+	}
+
+	// 5. Connection ID
+	// Started only has a resolution of seconds, so use the ID as a final
+	// criteria in order to get the same output for the same connections.
+	return a[i].ID < a[j].ID
 }
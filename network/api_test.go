@@ -18,6 +18,45 @@ func TestDebugInfoFormatting(t *testing.T) {
 	fmt.Println(buildNetworkDebugInfoData(connectionTestData))
 }
 
+func TestDebugInfoOrdering(t *testing.T) {
+	// Create connections of the same process that were started in the same
+	// second, so that only the connection ID can define the order.
+	newTestConn := func(localPort uint16) *Connection {
+		return &Connection{
+			ID:         fmt.Sprintf("17-192.168.0.176-%d-192.168.0.1-53", localPort),
+			Scope:      "IL",
+			IPVersion:  4,
+			IPProtocol: 17,
+			LocalIP:    net.ParseIP("192.168.0.176"),
+			LocalPort:  localPort,
+			Entity: &intel.Entity{
+				Protocol: 17,
+				Port:     53,
+				IP:       net.ParseIP("192.168.0.1"),
+			},
+			Verdict: 2,
+			Started: 1614010349,
+			ProcessContext: ProcessContext{
+				ProcessName: "systemd-resolved",
+				ProfileName: "Systemd Resolved",
+				BinaryPath:  "/usr/lib/systemd/systemd-resolved",
+				PID:         553,
+				Profile:     "_unidentified",
+				Source:      "local",
+			},
+		}
+	}
+	a := newTestConn(40001)
+	b := newTestConn(40002)
+	c := newTestConn(40003)
+
+	forward := buildNetworkDebugInfoData([]*Connection{a, b, c})
+	backward := buildNetworkDebugInfoData([]*Connection{c, b, a})
+	if forward != backward {
+		t.Errorf("debug info output depends on input order:\n%s\n\n---\n\n%s", forward, backward)
+	}
+}
+
 var connectionTestData = []*Connection{
 	{
 		ID:         "17-255.255.255.255-29810-192.168.0.23-40672",